@@ -1,7 +1,7 @@
 use std::{
-    cell::Cell,
+    cell::{Cell, Ref, RefCell, RefMut},
     fmt::Debug,
-    ops::{Deref, DerefMut},
+    rc::{Rc, Weak},
 };
 
 pub trait SignalDeriveBase {
@@ -31,46 +31,84 @@ pub trait Derive<Output: Copy>: AsSignalDeriveBase {
 impl<Output: Copy, T: AsSignalDeriveBase> Derive<Output> for T {}
 
 #[derive(Debug)]
-pub struct Signal<T> {
+pub(crate) struct SignalData<T> {
     inner: T,
     generation: u32,
 }
 
+/// A reactive cell. Reads and writes go through `&self` (the value and
+/// generation counter live behind a shared `Rc<RefCell<_>>`), so a `Signal`
+/// can be cheaply cloned and its [`SignalRef`] outlive any particular
+/// borrow of the `Signal` itself without dangling.
+///
+/// The `RefCell` means aliasing is checked at runtime instead of compile
+/// time: holding a [`Signal::get`]/[`Signal::get_mut`] guard across a call
+/// that writes to the same `Signal` panics (`already borrowed`) rather than
+/// failing to build. Keep borrows short-lived and don't nest them.
+#[derive(Debug)]
+pub struct Signal<T> {
+    data: Rc<RefCell<SignalData<T>>>,
+}
+
 impl<T> Signal<T> {
     pub fn new(value: T) -> Self {
         Self {
-            inner: value,
-            generation: 1,
+            data: Rc::new(RefCell::new(SignalData {
+                inner: value,
+                generation: 1,
+            })),
         }
     }
 
     #[inline(always)]
-    pub fn flag_updated(&mut self) {
-        self.generation = self.generation.wrapping_add(1);
+    pub fn flag_updated(&self) {
+        let mut data = self.data.borrow_mut();
+        data.generation = data.generation.wrapping_add(1);
     }
 
     #[inline]
-    pub fn set(&mut self, value: T) {
+    pub fn set(&self, value: T) {
         self.flag_updated();
 
-        self.inner = value;
+        self.data.borrow_mut().inner = value;
     }
 
     #[inline]
-    pub fn get(&self) -> &T {
-        &self.inner
+    pub fn get(&self) -> Ref<'_, T> {
+        Ref::map(self.data.borrow(), |data| &data.inner)
     }
 
     #[inline]
-    pub fn get_mut(&mut self) -> &mut T {
+    pub fn get_mut(&self) -> RefMut<'_, T> {
         self.flag_updated();
 
-        &mut self.inner
+        RefMut::map(self.data.borrow_mut(), |data| &mut data.inner)
     }
 
     #[inline]
     pub fn generation(&self) -> u32 {
-        self.generation
+        self.data.borrow().generation
+    }
+}
+
+impl<T: PartialEq> Signal<T> {
+    /// Like [`Signal::set`], but only bumps the generation when `value` is
+    /// actually different from the current one. Use this in derive chains to
+    /// avoid waking dependents over a no-op write.
+    #[inline]
+    pub fn set_if_changed(&self, value: T) {
+        if *self.get() != value {
+            self.set(value);
+        }
+    }
+}
+
+impl<T> Clone for Signal<T> {
+    /// Cheap: clones the shared handle, not the underlying value.
+    fn clone(&self) -> Self {
+        Self {
+            data: Rc::clone(&self.data),
+        }
     }
 }
 
@@ -78,30 +116,33 @@ impl<T: Copy> AsSignalDeriveBase for Signal<T> {
     type DeriveBase = SignalRef<T>;
 
     fn as_derive_base(&self) -> Self::DeriveBase {
+        let data = self.data.borrow();
+
         Self::DeriveBase {
-            inner: self as *const Signal<T>,
+            inner: Rc::downgrade(&self.data),
+            fallback: Cell::new((data.generation, data.inner)),
         }
     }
 }
 
-impl<T> Deref for Signal<T> {
-    type Target = T;
-
-    fn deref(&self) -> &Self::Target {
-        self.get()
-    }
+/// A weak, safe handle to a [`Signal`]'s value and generation. Unlike a raw
+/// pointer, this never dangles: once the owning `Signal` is dropped,
+/// `inner` simply fails to upgrade and reads fall back to the last value
+/// observed before that happened.
+pub struct SignalRef<T> {
+    inner: Weak<RefCell<SignalData<T>>>,
+    fallback: Cell<(u32, T)>,
 }
 
-impl<T> DerefMut for Signal<T> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        self.get_mut()
+impl<T: Copy> SignalRef<T> {
+    fn refresh_fallback(&self) {
+        if let Some(data) = self.inner.upgrade() {
+            let data = data.borrow();
+            self.fallback.set((data.generation, data.inner));
+        }
     }
 }
 
-pub struct SignalRef<T> {
-    inner: *const Signal<T>,
-}
-
 impl<T: Copy> SignalDeriveBase for SignalRef<T> {
     type Generation = u32;
     type Value = T;
@@ -111,18 +152,20 @@ impl<T: Copy> SignalDeriveBase for SignalRef<T> {
     }
 
     fn current_generation(&self) -> Self::Generation {
-        unsafe { &*self.inner as &Signal<T> }.generation()
+        self.refresh_fallback();
+        self.fallback.get().0
     }
 
     fn get(&self) -> Self::Value {
-        *unsafe { &*self.inner as &Signal<T> }.get()
+        self.refresh_fallback();
+        self.fallback.get().1
     }
 }
 
 pub struct Derived<Base: SignalDeriveBase, Output: Copy> {
     base: Base,
     output: Signal<Cell<Output>>,
-    output_gen: Base::Generation,
+    output_gen: Cell<Base::Generation>,
     function: Box<dyn Fn(Base::Value) -> Output>,
 }
 
@@ -138,7 +181,7 @@ impl<Base: SignalDeriveBase, Output: Copy + Debug> Debug for Derived<Base, Outpu
 impl<Base: SignalDeriveBase, Output: Copy> Derived<Base, Output> {
     pub(crate) fn new<F: Fn(Base::Value) -> Output + 'static>(base: Base, function: F) -> Self {
         let output = Signal::new(Cell::new(function(base.get())));
-        let output_gen = base.current_generation();
+        let output_gen = Cell::new(base.current_generation());
         let function = Box::new(function);
 
         Derived {
@@ -155,14 +198,15 @@ impl<Base: SignalDeriveBase, Output: Copy> Derived<Base, Output> {
 
     pub fn get(&self) -> Output {
         if self.input_changed() {
-            (*self.output).set((self.function)(self.base.get()));
+            self.output.set(Cell::new((self.function)(self.base.get())));
+            self.output_gen.set(self.base.current_generation());
         }
 
-        (*self.output).get()
+        self.output.get().get()
     }
 
     pub fn input_changed(&self) -> bool {
-        !self.base.compare_generation(self.output_gen)
+        !self.base.compare_generation(self.output_gen.get())
     }
 }
 
@@ -170,14 +214,22 @@ impl<Base: SignalDeriveBase, Output: Copy> AsSignalDeriveBase for Derived<Base,
     type DeriveBase = DerivedSignalRef<Output>;
 
     fn as_derive_base(&self) -> Self::DeriveBase {
-        Self::DeriveBase {
-            inner: &self.output as *const Signal<Cell<Output>>,
-        }
+        self.output.as_derived_ref()
     }
 }
 
 pub struct DerivedSignalRef<T: Copy> {
-    inner: *const Signal<Cell<T>>,
+    inner: Weak<RefCell<SignalData<Cell<T>>>>,
+    fallback: Cell<(u32, T)>,
+}
+
+impl<T: Copy> DerivedSignalRef<T> {
+    fn refresh_fallback(&self) {
+        if let Some(data) = self.inner.upgrade() {
+            let data = data.borrow();
+            self.fallback.set((data.generation, data.inner.get()));
+        }
+    }
 }
 
 impl<T: Copy> SignalDeriveBase for DerivedSignalRef<T> {
@@ -189,39 +241,311 @@ impl<T: Copy> SignalDeriveBase for DerivedSignalRef<T> {
     }
 
     fn current_generation(&self) -> Self::Generation {
-        unsafe { &*self.inner as &Signal<Cell<T>> }.generation()
+        self.refresh_fallback();
+        self.fallback.get().0
     }
 
     fn get(&self) -> Self::Value {
-        unsafe { &*self.inner as &Signal<Cell<T>> }.get().get()
+        self.refresh_fallback();
+        self.fallback.get().1
+    }
+}
+
+impl<T: Copy> Signal<Cell<T>> {
+    /// Shared by [`Derived`], [`EqDerived`] and [`RefDerived`], whose output signal always
+    /// has this `Signal<Cell<Output>>` shape.
+    fn as_derived_ref(&self) -> DerivedSignalRef<T> {
+        let data = self.data.borrow();
+
+        DerivedSignalRef {
+            inner: Rc::downgrade(&self.data),
+            fallback: Cell::new((data.generation, data.inner.get())),
+        }
     }
 }
 
-impl<A: AsSignalDeriveBase, B: AsSignalDeriveBase> AsSignalDeriveBase for (&A, &B) {
-    type DeriveBase = (A::DeriveBase, B::DeriveBase);
+impl<T> Signal<T> {
+    /// Like [`Derive::derive`], but reads the source by reference instead of
+    /// copying it out, so `T` does not need to be `Copy`. This is what lets
+    /// heap-allocated state such as `String` or `Vec<T>` feed a derive chain.
+    ///
+    /// Unlike [`Derive::derive`], whose derive base only holds a [`Weak`]
+    /// reference to its source, the returned [`RefDerived`] holds a strong
+    /// clone of this `Signal` and so keeps it alive for as long as the
+    /// derived value exists.
+    pub fn derive_ref<F, Output: Copy>(&self, function: F) -> RefDerived<T, Output>
+    where
+        F: Fn(&T) -> Output + 'static,
+    {
+        RefDerived::new(self.clone(), function)
+    }
+}
+
+/// A [`Derived`]-like value computed from a non-`Copy` [`Signal`] source by
+/// borrowing it (`Fn(&T) -> Output`) rather than copying it out. Created via
+/// [`Signal::derive_ref`].
+///
+/// Note this holds a strong `Signal<T>` clone rather than a [`Weak`] one, so
+/// unlike every other derive base in this crate it keeps its source alive
+/// for as long as the `RefDerived` itself lives.
+pub struct RefDerived<T, Output: Copy> {
+    source: Signal<T>,
+    output: Signal<Cell<Output>>,
+    output_gen: Cell<u32>,
+    function: Box<dyn Fn(&T) -> Output>,
+}
+
+impl<T, Output: Copy + Debug> Debug for RefDerived<T, Output> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RefDerived")
+            .field("output", &self.output)
+            .field("output_gen", &self.output_gen)
+            .finish()
+    }
+}
+
+impl<T, Output: Copy> RefDerived<T, Output> {
+    fn new<F: Fn(&T) -> Output + 'static>(source: Signal<T>, function: F) -> Self {
+        let output = Signal::new(Cell::new(function(&source.get())));
+        let output_gen = Cell::new(source.generation());
+        let function = Box::new(function);
+
+        RefDerived {
+            source,
+            output,
+            output_gen,
+            function,
+        }
+    }
+
+    pub fn output_signal(&self) -> &Signal<Cell<Output>> {
+        &self.output
+    }
+
+    pub fn get(&self) -> Output {
+        if self.input_changed() {
+            self.output.set(Cell::new((self.function)(&self.source.get())));
+            self.output_gen.set(self.source.generation());
+        }
+
+        self.output.get().get()
+    }
+
+    pub fn input_changed(&self) -> bool {
+        self.source.generation() != self.output_gen.get()
+    }
+}
+
+impl<T, Output: Copy> AsSignalDeriveBase for RefDerived<T, Output> {
+    type DeriveBase = DerivedSignalRef<Output>;
 
     fn as_derive_base(&self) -> Self::DeriveBase {
-        (self.0.as_derive_base(), self.1.as_derive_base())
+        self.output.as_derived_ref()
     }
 }
 
-impl<A: SignalDeriveBase, B: SignalDeriveBase> SignalDeriveBase for (A, B) {
-    type Generation = (A::Generation, B::Generation);
-    type Value = (A::Value, B::Value);
+pub trait DeriveEq<Output: Copy + PartialEq>: AsSignalDeriveBase {
+    fn derive_eq<F>(&self, function: F) -> EqDerived<Self::DeriveBase, Output>
+    where
+        F: Fn(<Self::DeriveBase as SignalDeriveBase>::Value) -> Output + 'static,
+    {
+        EqDerived::new(self.as_derive_base(), function)
+    }
+}
 
-    fn compare_generation(&self, other: Self::Generation) -> bool {
-        self.0.compare_generation(other.0) && self.1.compare_generation(other.1)
+impl<Output: Copy + PartialEq, T: AsSignalDeriveBase> DeriveEq<Output> for T {}
+
+/// Like [`Derived`], but only wakes dependents when the recomputed value
+/// actually differs from the previous one (via [`Signal::set_if_changed`]).
+/// Use this instead of [`Derive::derive`] when `Output` is cheap to compare
+/// and you want to stop a no-op recompute from propagating down a derive
+/// chain.
+pub struct EqDerived<Base: SignalDeriveBase, Output: Copy + PartialEq> {
+    base: Base,
+    output: Signal<Cell<Output>>,
+    output_gen: Cell<Base::Generation>,
+    function: Box<dyn Fn(Base::Value) -> Output>,
+}
+
+impl<Base: SignalDeriveBase, Output: Copy + PartialEq + Debug> Debug for EqDerived<Base, Output> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EqDerived")
+            .field("output", &self.output)
+            .field("output_gen", &self.output_gen)
+            .finish()
     }
+}
 
-    fn current_generation(&self) -> Self::Generation {
-        (self.0.current_generation(), self.1.current_generation())
+impl<Base: SignalDeriveBase, Output: Copy + PartialEq> EqDerived<Base, Output> {
+    pub(crate) fn new<F: Fn(Base::Value) -> Output + 'static>(base: Base, function: F) -> Self {
+        let output = Signal::new(Cell::new(function(base.get())));
+        let output_gen = Cell::new(base.current_generation());
+        let function = Box::new(function);
+
+        EqDerived {
+            base,
+            output,
+            output_gen,
+            function,
+        }
     }
 
-    fn get(&self) -> Self::Value {
-        (self.0.get(), self.1.get())
+    pub fn output_signal(&self) -> &Signal<Cell<Output>> {
+        &self.output
+    }
+
+    pub fn get(&self) -> Output {
+        if self.input_changed() {
+            let value = (self.function)(self.base.get());
+            self.output.set_if_changed(Cell::new(value));
+            self.output_gen.set(self.base.current_generation());
+        }
+
+        self.output.get().get()
+    }
+
+    pub fn input_changed(&self) -> bool {
+        !self.base.compare_generation(self.output_gen.get())
+    }
+}
+
+impl<Base: SignalDeriveBase, Output: Copy + PartialEq> AsSignalDeriveBase
+    for EqDerived<Base, Output>
+{
+    type DeriveBase = DerivedSignalRef<Output>;
+
+    fn as_derive_base(&self) -> Self::DeriveBase {
+        self.output.as_derived_ref()
+    }
+}
+
+/// Generates the `AsSignalDeriveBase`/`SignalDeriveBase` impls for a tuple of
+/// the given arity, flattening it into a matching `Generation`/`Value` tuple
+/// and AND-ing all `compare_generation` results (short-circuiting left to
+/// right).
+macro_rules! impl_tuple_signal_derive_base {
+    ($($idx:tt => $name:ident),+) => {
+        impl<$($name: AsSignalDeriveBase),+> AsSignalDeriveBase for ($(&$name,)+) {
+            type DeriveBase = ($($name::DeriveBase,)+);
+
+            fn as_derive_base(&self) -> Self::DeriveBase {
+                ($(self.$idx.as_derive_base(),)+)
+            }
+        }
+
+        impl<$($name: SignalDeriveBase),+> SignalDeriveBase for ($($name,)+) {
+            type Generation = ($($name::Generation,)+);
+            type Value = ($($name::Value,)+);
+
+            fn compare_generation(&self, other: Self::Generation) -> bool {
+                true $(&& self.$idx.compare_generation(other.$idx))+
+            }
+
+            fn current_generation(&self) -> Self::Generation {
+                ($(self.$idx.current_generation(),)+)
+            }
+
+            fn get(&self) -> Self::Value {
+                ($(self.$idx.get(),)+)
+            }
+        }
+    };
+}
+
+impl_tuple_signal_derive_base!(0 => A, 1 => B);
+impl_tuple_signal_derive_base!(0 => A, 1 => B, 2 => C);
+impl_tuple_signal_derive_base!(0 => A, 1 => B, 2 => C, 3 => D);
+impl_tuple_signal_derive_base!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E);
+impl_tuple_signal_derive_base!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F);
+impl_tuple_signal_derive_base!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G);
+impl_tuple_signal_derive_base!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H);
+impl_tuple_signal_derive_base!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I);
+impl_tuple_signal_derive_base!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I, 9 => J);
+impl_tuple_signal_derive_base!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I, 9 => J, 10 => K);
+impl_tuple_signal_derive_base!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I, 9 => J, 10 => K, 11 => L);
+
+/// An imperative side effect driven by a [`SignalDeriveBase`] source. Created
+/// through [`create_effect`] and polled by [`Runtime::flush`]; runs its
+/// closure once immediately (like [`Derived::new`]) and again on each flush
+/// that finds the source's generation advanced since the last run.
+pub struct Effect<Base: SignalDeriveBase> {
+    base: Base,
+    closure: Box<dyn FnMut(Base::Value)>,
+    last_generation: Base::Generation,
+}
+
+impl<Base: SignalDeriveBase> Effect<Base> {
+    fn new<F: FnMut(Base::Value) + 'static>(base: Base, mut closure: F) -> Self {
+        closure(base.get());
+        let last_generation = base.current_generation();
+        let closure = Box::new(closure);
+
+        Effect {
+            base,
+            closure,
+            last_generation,
+        }
+    }
+
+    fn flush(&mut self) {
+        if !self.base.compare_generation(self.last_generation) {
+            (self.closure)(self.base.get());
+            self.last_generation = self.base.current_generation();
+        }
+    }
+}
+
+/// Type-erased handle so a [`Runtime`] can hold effects over unrelated
+/// [`SignalDeriveBase`] types in a single `Vec`.
+trait FlushEffect {
+    fn flush(&mut self);
+}
+
+impl<Base: SignalDeriveBase> FlushEffect for Effect<Base> {
+    fn flush(&mut self) {
+        Effect::flush(self)
+    }
+}
+
+/// Holds every [`Effect`] registered on the current thread. There is one
+/// instance per thread, reached through [`create_effect`] and [`Runtime::flush`].
+#[derive(Default)]
+pub struct Runtime {
+    effects: Vec<Box<dyn FlushEffect>>,
+}
+
+impl Runtime {
+    /// Polls every effect registered on this thread, running the ones whose
+    /// source has advanced since it was last checked.
+    pub fn flush() {
+        RUNTIME.with(|runtime| {
+            for effect in runtime.borrow_mut().effects.iter_mut() {
+                effect.flush();
+            }
+        });
     }
 }
 
+thread_local! {
+    static RUNTIME: RefCell<Runtime> = RefCell::new(Runtime::default());
+}
+
+/// Registers an effect on the current thread's [`Runtime`]. `closure` runs
+/// immediately with `source`'s current value, then again on every
+/// [`Runtime::flush`] call where `source`'s generation has advanced.
+pub fn create_effect<Source, F>(source: &Source, closure: F)
+where
+    Source: AsSignalDeriveBase,
+    Source::DeriveBase: 'static,
+    F: FnMut(<Source::DeriveBase as SignalDeriveBase>::Value) + 'static,
+{
+    let effect = Effect::new(source.as_derive_base(), closure);
+
+    RUNTIME.with(|runtime| {
+        runtime.borrow_mut().effects.push(Box::new(effect));
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Signal;
@@ -230,15 +554,192 @@ mod tests {
     fn test_memos() {
         use crate::Derive;
 
-        let mut number = Signal::new(1);
+        let number = Signal::new(1);
         let double = number.derive(|number| number * 2);
 
-        assert_eq!(*number, 1);
+        assert_eq!(*number.get(), 1);
         assert_eq!(double.get(), 2);
 
-        *number += 1;
+        *number.get_mut() += 1;
 
-        assert_eq!(*number, 2);
+        assert_eq!(*number.get(), 2);
         assert_eq!(double.get(), 4);
     }
+
+    #[test]
+    fn test_derive_does_not_recompute_once_caught_up() {
+        use crate::Derive;
+        use std::{cell::Cell, rc::Rc};
+
+        let number = Signal::new(1);
+        let calls = Rc::new(Cell::new(0));
+
+        let calls_for_derive = Rc::clone(&calls);
+        let double = number.derive(move |number| {
+            calls_for_derive.set(calls_for_derive.get() + 1);
+            number * 2
+        });
+
+        assert_eq!(double.get(), 2);
+        assert_eq!(calls.get(), 1);
+
+        *number.get_mut() += 1;
+        assert_eq!(double.get(), 4);
+        assert_eq!(calls.get(), 2);
+
+        // No further input changes, so repeated polling must not recompute.
+        double.get();
+        double.get();
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "already borrowed")]
+    fn test_overlapping_borrows_panic() {
+        let number = Signal::new(1);
+
+        let _read = number.get();
+        number.set(2);
+    }
+
+    #[test]
+    fn test_eq_derive_skips_unchanged_output() {
+        use crate::DeriveEq;
+
+        let number = Signal::new(3);
+        let parity = number.derive_eq(|number| number % 2 == 0);
+
+        assert!(!parity.get());
+        let generation_before = parity.output_signal().generation();
+
+        // 3 -> 5 is still odd, so the derived output does not change.
+        *number.get_mut() += 2;
+        assert!(!parity.get());
+        assert_eq!(parity.output_signal().generation(), generation_before);
+
+        // 5 -> 6 flips parity, so this time the output generation advances.
+        *number.get_mut() += 1;
+        assert!(parity.get());
+        assert!(parity.output_signal().generation() != generation_before);
+    }
+
+    #[test]
+    fn test_eq_derive_does_not_recompute_once_caught_up() {
+        use crate::DeriveEq;
+        use std::{cell::Cell, rc::Rc};
+
+        let number = Signal::new(1);
+        let calls = Rc::new(Cell::new(0));
+
+        let calls_for_derive = Rc::clone(&calls);
+        let parity = number.derive_eq(move |number| {
+            calls_for_derive.set(calls_for_derive.get() + 1);
+            number % 2 == 0
+        });
+
+        assert!(!parity.get());
+        assert_eq!(calls.get(), 1);
+
+        *number.get_mut() += 1;
+        assert!(parity.get());
+        assert_eq!(calls.get(), 2);
+
+        // No further input changes, so repeated polling must not recompute.
+        parity.get();
+        parity.get();
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_effect_flush() {
+        use crate::{create_effect, Runtime};
+        use std::{cell::RefCell, rc::Rc};
+
+        let number = Signal::new(1);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        let seen_for_effect = Rc::clone(&seen);
+        create_effect(&number, move |value| seen_for_effect.borrow_mut().push(value));
+
+        // Runs once immediately on creation.
+        assert_eq!(*seen.borrow(), vec![1]);
+
+        *number.get_mut() += 1;
+        Runtime::flush();
+        assert_eq!(*seen.borrow(), vec![1, 2]);
+
+        // Flushing again without a change should not rerun the closure.
+        Runtime::flush();
+        assert_eq!(*seen.borrow(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_derive_outlives_dropped_source() {
+        use crate::{AsSignalDeriveBase, SignalDeriveBase};
+
+        let number = Signal::new(10);
+        let derive_base = number.as_derive_base();
+
+        drop(number);
+
+        // The source is gone, so reads fall back to the last value it saw
+        // instead of dereferencing a dangling pointer.
+        assert_eq!(derive_base.get(), 10);
+    }
+
+    #[test]
+    fn test_derive_ref_from_non_copy_source() {
+        let name = Signal::new(String::from("hello"));
+        let length = name.derive_ref(|name| name.len());
+
+        assert_eq!(length.get(), 5);
+
+        name.set(String::from("hello world"));
+
+        assert_eq!(length.get(), 11);
+    }
+
+    #[test]
+    fn test_derive_ref_does_not_recompute_once_caught_up() {
+        use std::{cell::Cell, rc::Rc};
+
+        let name = Signal::new(String::from("hello"));
+        let calls = Rc::new(Cell::new(0));
+
+        let calls_for_derive = Rc::clone(&calls);
+        let length = name.derive_ref(move |name| {
+            calls_for_derive.set(calls_for_derive.get() + 1);
+            name.len()
+        });
+
+        assert_eq!(length.get(), 5);
+        assert_eq!(calls.get(), 1);
+
+        name.set(String::from("hello world"));
+        assert_eq!(length.get(), 11);
+        assert_eq!(calls.get(), 2);
+
+        // No further input changes, so repeated polling must not recompute.
+        length.get();
+        length.get();
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_derive_from_a_four_tuple() {
+        use crate::Derive;
+
+        let a = Signal::new(1);
+        let b = Signal::new(2);
+        let c = Signal::new(3);
+        let d = Signal::new(4);
+
+        let sum = (&a, &b, &c, &d).derive(|(a, b, c, d)| a + b + c + d);
+
+        assert_eq!(sum.get(), 10);
+
+        *d.get_mut() += 10;
+
+        assert_eq!(sum.get(), 20);
+    }
 }